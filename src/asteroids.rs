@@ -0,0 +1,104 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy_prototype_lyon::prelude::*;
+use rand::Rng;
+
+use crate::{
+    audio::{AudioEvent, Sound},
+    boundary::Bounding,
+    collision::{Collidable, CollisionPlugin, HitEvent, PreviousPosition},
+    particles::emit_burst,
+    player::Score,
+    BoundaryRemoval, Random, Spatial, Velocity,
+};
+
+/// Below this radius a hit asteroid is destroyed outright instead of splitting.
+const MIN_SPLIT_RADIUS: f32 = 15.0;
+const CHILD_RADIUS_SCALE: f32 = 0.6;
+const CHILD_SPREAD_SPEED: f32 = 40.0;
+/// Score awarded per unit of asteroid radius destroyed.
+const SCORE_PER_RADIUS: f32 = 2.0;
+
+#[derive(Debug, Component, Default)]
+pub(crate) struct Bullet;
+
+#[derive(Debug, Component, Default)]
+pub(crate) struct Asteroid;
+
+/// Turns bullet/asteroid collisions into despawns, splits and score-worthy debris.
+pub(crate) struct AsteroidPlugin;
+
+impl Plugin for AsteroidPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(CollisionPlugin::<Bullet, Asteroid>::new())
+            .add_system(asteroid_hit_system);
+    }
+}
+
+fn asteroid_hit_system(
+    mut commands: Commands,
+    mut hits: EventReader<HitEvent<Bullet, Asteroid>>,
+    mut rng: Local<Random>,
+    mut score: ResMut<Score>,
+    mut explosions: EventWriter<AudioEvent>,
+    asteroids: Query<(&Spatial, &Velocity)>,
+) {
+    let mut destroyed = HashSet::new();
+
+    for hit in hits.iter() {
+        commands.entity(hit.hittable()).despawn();
+
+        // Two bullets can hit the same asteroid in one frame; despawn
+        // commands are deferred, so without this the second event would
+        // still see it alive and destroy/split/score it all over again.
+        if !destroyed.insert(hit.hurtable()) {
+            continue;
+        }
+        commands.entity(hit.hurtable()).despawn();
+
+        let Ok((spatial, velocity)) = asteroids.get(hit.hurtable()) else {
+            continue;
+        };
+
+        emit_burst(&mut commands, &mut rng, spatial.position);
+        explosions.send(AudioEvent::new(Sound::Explosion, spatial.position.x));
+        score.0 += (spatial.radius * SCORE_PER_RADIUS) as u32;
+
+        if spatial.radius < MIN_SPLIT_RADIUS {
+            continue;
+        }
+
+        let child_radius = spatial.radius * CHILD_RADIUS_SCALE;
+        for _ in 0..rng.gen_range(2..=3) {
+            let perpendicular = Vec2::new(-velocity.0.y, velocity.0.x).normalize_or_zero();
+            let spread = rng.gen_range(-CHILD_SPREAD_SPEED..CHILD_SPREAD_SPEED);
+            let child_velocity = velocity.0 + perpendicular * spread;
+
+            spawn_asteroid(&mut commands, spatial.position, child_velocity, child_radius);
+        }
+    }
+}
+
+pub(crate) fn spawn_asteroid(commands: &mut Commands, position: Vec2, velocity: Vec2, radius: f32) {
+    commands
+        .spawn_bundle(GeometryBuilder::build_as(
+            &shapes::Circle {
+                radius,
+                center: Vec2::ZERO,
+            },
+            DrawMode::Fill(FillMode::color(Color::BLACK)),
+            Transform::default().with_translation(Vec3::new(position.x, position.y, 0.0)),
+        ))
+        .insert(Spatial {
+            position,
+            rotation: 0.0,
+            radius,
+        })
+        .insert(Velocity(velocity))
+        .insert(Bounding(radius))
+        .insert(Collidable)
+        .insert(PreviousPosition(position))
+        .insert(Asteroid)
+        .insert(BoundaryRemoval);
+}
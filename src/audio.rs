@@ -0,0 +1,166 @@
+use bevy::audio::{Audio, AudioSource, PlaybackSettings};
+use bevy::prelude::*;
+
+use crate::{
+    asteroids::{Asteroid, Bullet},
+    player::{Lives, Score},
+    ufo::Ufo,
+    Player, Spatial, ThrustEngine,
+};
+
+/// One-shot sounds the gameplay systems can ask to have played.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Sound {
+    Shot,
+    Explosion,
+}
+
+#[derive(Debug)]
+pub(crate) struct AudioEvent {
+    sound: Sound,
+    position_x: f32,
+}
+
+impl AudioEvent {
+    pub(crate) fn new(sound: Sound, position_x: f32) -> Self {
+        Self { sound, position_x }
+    }
+}
+
+#[derive(Debug, Default)]
+struct SoundAssets {
+    shot: Handle<AudioSource>,
+    explosion: Handle<AudioSource>,
+    thrust: Handle<AudioSource>,
+}
+
+/// Score interval at which `announce_score_system` speaks the score. It
+/// remembers the last milestone reached, so each one is announced only once.
+const SCORE_MILESTONE: u32 = 500;
+
+/// How often `thrust_audio_system` retriggers the thrust clip while
+/// `ThrustEngine::on` stays true.
+const THRUST_RETRIGGER: f32 = 0.3;
+
+pub(crate) struct AudioPlugin;
+
+impl Plugin for AudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AudioEvent>()
+            .init_resource::<SoundAssets>()
+            .add_startup_system(load_sounds_system)
+            .add_system(play_audio_system)
+            .add_system(thrust_audio_system)
+            .add_system(announce_score_system)
+            .add_system(announce_lives_system)
+            .add_system(announce_wave_cleared_system);
+    }
+}
+
+fn load_sounds_system(asset_server: Res<AssetServer>, mut sounds: ResMut<SoundAssets>) {
+    sounds.shot = asset_server.load("sounds/shot.ogg");
+    sounds.explosion = asset_server.load("sounds/explosion.ogg");
+    sounds.thrust = asset_server.load("sounds/thrust.ogg");
+}
+
+/// Approximates the "simple stereo panning" ask as a volume falloff toward
+/// the edges of the window: this Bevy version's `Audio` resource only
+/// exposes a single playback volume, not a left/right pan control.
+fn pan_volume(position_x: f32, window: &WindowDescriptor) -> f32 {
+    let half_width = (window.width / 2.0).max(1.0);
+    let pan = (position_x / half_width).clamp(-1.0, 1.0);
+    1.0 - pan.abs() * 0.5
+}
+
+fn play_audio_system(
+    audio: Res<Audio>,
+    sounds: Res<SoundAssets>,
+    window: Res<WindowDescriptor>,
+    mut events: EventReader<AudioEvent>,
+) {
+    for event in events.iter() {
+        let source = match event.sound {
+            Sound::Shot => sounds.shot.clone(),
+            Sound::Explosion => sounds.explosion.clone(),
+        };
+
+        audio.play_with_settings(
+            source,
+            PlaybackSettings {
+                volume: pan_volume(event.position_x, &window),
+                ..Default::default()
+            },
+        );
+    }
+}
+
+fn thrust_audio_system(
+    time: Res<Time>,
+    audio: Res<Audio>,
+    sounds: Res<SoundAssets>,
+    window: Res<WindowDescriptor>,
+    mut since_last_play: Local<f32>,
+    ship: Query<(&ThrustEngine, &Spatial), With<Player>>,
+) {
+    let Ok((thrust, spatial)) = ship.get_single() else {
+        return;
+    };
+
+    if !thrust.on {
+        *since_last_play = THRUST_RETRIGGER;
+        return;
+    }
+
+    // This Bevy version's `Audio` resource can't stop or pause a sound once
+    // started, so instead of a single looping clip this retriggers a short
+    // one-shot on an interval for as long as thrust stays on, keeping the
+    // sound actually gated on `ThrustEngine::on`.
+    *since_last_play += time.delta_seconds();
+    if *since_last_play >= THRUST_RETRIGGER {
+        *since_last_play = 0.0;
+        audio.play_with_settings(
+            sounds.thrust.clone(),
+            PlaybackSettings {
+                volume: pan_volume(spatial.position.x, &window),
+                ..Default::default()
+            },
+        );
+    }
+}
+
+fn announce_score_system(mut last_milestone: Local<u32>, score: Res<Score>) {
+    let milestone = score.0 / SCORE_MILESTONE;
+    if milestone > *last_milestone {
+        *last_milestone = milestone;
+        speak(&format!("Score {}", score.0));
+    }
+}
+
+fn announce_lives_system(mut last_lives: Local<Option<u32>>, lives: Query<&Lives, With<Player>>) {
+    let Ok(lives) = lives.get_single() else {
+        return;
+    };
+
+    if last_lives.is_some_and(|previous| lives.0 < previous) {
+        speak("Life lost");
+    }
+    *last_lives = Some(lives.0);
+}
+
+fn announce_wave_cleared_system(
+    mut was_empty: Local<Option<bool>>,
+    threats: Query<(), Or<(With<Asteroid>, With<Ufo>)>>,
+) {
+    let empty_now = threats.iter().next().is_none();
+    if *was_empty == Some(false) && empty_now {
+        speak("Wave cleared");
+    }
+    *was_empty = Some(empty_now);
+}
+
+/// Announces a state change through the screen-reader layer. Stands in for a
+/// real `bevy_tts`-style backend, which would replace this with an actual
+/// speech call.
+fn speak(text: &str) {
+    info!(target: "accessibility", "{text}");
+}
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Collision radius of an entity, kept in sync with its `Spatial::radius`.
+///
+/// Separate from `Spatial` so collision code only needs to depend on this
+/// crate-local wrapper instead of the full spatial state.
+#[derive(Debug, Component, Deref, DerefMut)]
+pub struct Bounding(pub f32);
@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use bevy::{prelude::*, ecs::schedule::ScheduleLabel};
+use bevy::prelude::*;
 
 use crate::boundary::Bounding;
 
@@ -18,15 +18,16 @@ impl<Hittable: Component, Hurtable: Component> CollisionPlugin<Hittable, Hurtabl
 
 impl<Hittable: Component, Hurtable: Component> Plugin for CollisionPlugin<Hittable, Hurtable> {
     fn build(&self, app: &mut App) {
+        // Must run after "drawing" so `Transform` already holds this frame's
+        // synced position and `PreviousPosition` still holds the position
+        // `update_previous_position_system` captured before it, matching the
+        // segment the swept test assumes.
         app.add_event::<HitEvent<Hittable, Hurtable>>()
-            .add_systems(Update, collision_system::<Hittable, Hurtable>.in_set(CollisionSystemLabel));
+            .add_system(collision_system::<Hittable, Hurtable>.after("drawing"));
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet, ScheduleLabel)]
-pub struct CollisionSystemLabel;
-
-#[derive(Debug, Event)]
+#[derive(Debug)]
 pub struct HitEvent<A, B> {
     entities: (Entity, Entity),
     _phantom: PhantomData<(A, B)>,
@@ -45,14 +46,40 @@ impl<A, B> HitEvent<A, B> {
 #[derive(Debug, Component)]
 pub struct Collidable;
 
+/// The world-space position an entity occupied as of the previous physics
+/// step, captured before `Transform` is synced for the current frame.
+///
+/// Used by `collision_system` to treat a fast-moving hittable's motion as a
+/// line segment rather than a single point, so it can't tunnel through a
+/// hurtable between two frames.
+#[derive(Debug, Component, Default, Deref, DerefMut)]
+pub struct PreviousPosition(pub Vec2);
+
+/// Captures `Transform::translation` into `PreviousPosition` for every
+/// collidable entity. Must run at the end of the physics set, before
+/// boundary wrapping and the `Transform` sync in `drawing_system`, so that
+/// `PreviousPosition` still holds last frame's position when this frame's
+/// collisions are checked.
+pub fn update_previous_position_system(
+    mut query: Query<(&Transform, &mut PreviousPosition), With<Collidable>>,
+) {
+    for (transform, mut previous) in query.iter_mut() {
+        previous.0 = transform.translation.truncate();
+    }
+}
+
 fn collision_system<A: Component, B: Component>(
     mut hits: EventWriter<HitEvent<A, B>>,
-    hittables: Query<(Entity, &Transform, &Bounding), (With<Collidable>, With<A>)>,
+    hittables: Query<(Entity, &Transform, &PreviousPosition, &Bounding), (With<Collidable>, With<A>)>,
     hurtables: Query<(Entity, &Transform, &Bounding), (With<Collidable>, With<B>)>,
 ) {
-    for (hittable_entity, hit_transform, hit_bounds) in hittables.iter() {
+    for (hittable_entity, hit_transform, hit_previous, hit_bounds) in hittables.iter() {
+        let segment_end = hit_transform.translation.truncate();
+        let segment_start = hit_previous.0;
+
         for (hurtable_entity, hurt_transform, hurt_bounds) in hurtables.iter() {
-            let distance = (hit_transform.translation - hurt_transform.translation).length();
+            let center = hurt_transform.translation.truncate();
+            let distance = distance_to_segment(center, segment_start, segment_end);
             if distance < **hit_bounds + **hurt_bounds {
                 hits.send(HitEvent {
                     entities: (hittable_entity, hurtable_entity),
@@ -62,3 +89,16 @@ fn collision_system<A: Component, B: Component>(
         }
     }
 }
+
+/// Shortest distance from `point` to the segment `start..end`, falling back
+/// to a plain point-distance test when the segment has zero length.
+fn distance_to_segment(point: Vec2, start: Vec2, end: Vec2) -> f32 {
+    let segment = end - start;
+    let length_squared = segment.length_squared();
+    if length_squared == 0.0 {
+        return point.distance(start);
+    }
+
+    let t = ((point - start).dot(segment) / length_squared).clamp(0.0, 1.0);
+    point.distance(start + segment * t)
+}
@@ -7,7 +7,23 @@ use bevy_prototype_lyon::prelude::{
 };
 use rand::{prelude::SmallRng, Rng, SeedableRng};
 
-const TIME_STEP: f32 = 1.0 / 120.0;
+use asteroids::{spawn_asteroid, AsteroidPlugin, Bullet};
+use audio::{AudioEvent, AudioPlugin, Sound};
+use boundary::Bounding;
+use collision::{update_previous_position_system, Collidable, PreviousPosition};
+use particles::ParticlePlugin;
+use player::{Lives, PlayerPlugin, STARTING_LIVES};
+use ufo::{boids_system, UfoPlugin};
+
+mod asteroids;
+mod audio;
+mod boundary;
+mod collision;
+mod particles;
+mod player;
+mod ufo;
+
+pub(crate) const TIME_STEP: f32 = 1.0 / 120.0;
 
 fn main() {
     App::new()
@@ -20,6 +36,11 @@ fn main() {
         .add_plugins(DefaultPlugins)
         .add_plugin(ShapePlugin)
         .insert_resource(Random(SmallRng::from_entropy()))
+        .add_plugin(AsteroidPlugin)
+        .add_plugin(UfoPlugin)
+        .add_plugin(ParticlePlugin)
+        .add_plugin(PlayerPlugin)
+        .add_plugin(AudioPlugin)
         .add_startup_system(setup_system)
         .add_system_set(
             SystemSet::new()
@@ -36,9 +57,12 @@ fn main() {
                 .with_run_criteria(FixedTimestep::step(TIME_STEP.into()))
                 .label("physics")
                 .after("input")
+                .with_system(boids_system.before(speed_limit_system))
                 .with_system(damping_system.before(movement_system))
                 .with_system(speed_limit_system.before(movement_system))
-                .with_system(movement_system),
+                .with_system(movement_system)
+                .with_system(angular_movement_system)
+                .with_system(update_previous_position_system.after(movement_system)),
         )
         .add_system_set(
             SystemSet::new()
@@ -47,10 +71,18 @@ fn main() {
                 .with_system(boundary_remove_system)
                 .with_system(boundary_wrap_system),
         )
-        .add_system(drawing_system.after("wrap"))
+        .add_system(drawing_system.label("drawing").after("wrap"))
         .run();
 }
 
+/// The ship's outline, also reused by `particles` to build its death debris.
+pub(crate) const SHIP_OUTLINE: [Vec2; 4] = [
+    Vec2::ZERO,
+    Vec2::new(-8.0, -8.0),
+    Vec2::new(0.0, 12.0),
+    Vec2::new(8.0, -8.0),
+];
+
 fn setup_system(mut commands: Commands) {
     commands.spawn_bundle(OrthographicCameraBundle::new_2d());
 
@@ -58,11 +90,11 @@ fn setup_system(mut commands: Commands) {
         .spawn_bundle(GeometryBuilder::build_as(
             &{
                 let mut path_builder = PathBuilder::new();
-                path_builder.move_to(Vec2::ZERO);
-                path_builder.line_to(Vec2::new(-8.0, -8.0));
-                path_builder.line_to(Vec2::new(0.0, 12.0));
-                path_builder.line_to(Vec2::new(8.0, -8.0));
-                path_builder.line_to(Vec2::ZERO);
+                path_builder.move_to(SHIP_OUTLINE[0]);
+                path_builder.line_to(SHIP_OUTLINE[1]);
+                path_builder.line_to(SHIP_OUTLINE[2]);
+                path_builder.line_to(SHIP_OUTLINE[3]);
+                path_builder.line_to(SHIP_OUTLINE[0]);
                 let mut line = path_builder.build();
                 line.0 = line.0.transformed(&Rotation::new(Angle::degrees(-90.0)));
                 line
@@ -81,11 +113,18 @@ fn setup_system(mut commands: Commands) {
         .insert(ThrustEngine::new(1.5))
         .insert(SteeringControl(Angle::degrees(180.0)))
         .insert(Weapon::new(Duration::from_millis(100)))
-        .insert(BoundaryWrap);
+        .insert(BoundaryWrap)
+        .insert(Bounding(12.0))
+        .insert(Collidable)
+        .insert(Lives(STARTING_LIVES))
+        .insert(Player);
 }
 
+#[derive(Debug, Component, Default)]
+pub(crate) struct Player;
+
 #[derive(Debug, Deref, DerefMut)]
-struct Random(SmallRng);
+pub(crate) struct Random(SmallRng);
 
 impl FromWorld for Random {
     fn from_world(world: &mut World) -> Self {
@@ -97,14 +136,14 @@ impl FromWorld for Random {
 }
 
 #[derive(Debug, Component, Default)]
-struct Spatial {
-    position: Vec2,
-    rotation: f32,
-    radius: f32,
+pub(crate) struct Spatial {
+    pub(crate) position: Vec2,
+    pub(crate) rotation: f32,
+    pub(crate) radius: f32,
 }
 
 #[derive(Debug, Component, Default)]
-struct Velocity(Vec2);
+pub(crate) struct Velocity(pub(crate) Vec2);
 
 #[derive(Debug, Component, Default)]
 struct SpeedLimit(f32);
@@ -113,9 +152,12 @@ struct SpeedLimit(f32);
 struct Damping(f32);
 
 #[derive(Debug, Component, Default)]
-struct ThrustEngine {
+pub(crate) struct AngularVelocity(pub(crate) f32);
+
+#[derive(Debug, Component, Default)]
+pub(crate) struct ThrustEngine {
     force: f32,
-    on: bool,
+    pub(crate) on: bool,
 }
 
 impl ThrustEngine {
@@ -149,7 +191,7 @@ impl Weapon {
 struct BoundaryWrap;
 
 #[derive(Debug, Component, Default)]
-struct BoundaryRemoval;
+pub(crate) struct BoundaryRemoval;
 
 fn movement_system(mut query: Query<(&mut Spatial, &Velocity)>) {
     for (mut spatial, velocity) in query.iter_mut() {
@@ -157,6 +199,12 @@ fn movement_system(mut query: Query<(&mut Spatial, &Velocity)>) {
     }
 }
 
+fn angular_movement_system(mut query: Query<(&mut Spatial, &AngularVelocity)>) {
+    for (mut spatial, angular_velocity) in query.iter_mut() {
+        spatial.rotation += angular_velocity.0 * TIME_STEP;
+    }
+}
+
 fn speed_limit_system(mut query: Query<(&mut Velocity, &SpeedLimit)>) {
     for (mut velocity, speed_limit) in query.iter_mut() {
         velocity.0 = velocity.0.clamp_length_max(speed_limit.0);
@@ -181,6 +229,7 @@ fn thrust_system(mut query: Query<(&mut Velocity, &ThrustEngine, &Spatial)>) {
 fn weapon_system(
     time: Res<Time>,
     mut commands: Commands,
+    mut shots: EventWriter<AudioEvent>,
     mut query: Query<(&Spatial, &mut Weapon)>,
 ) {
     for (spatial, mut weapon) in query.iter_mut() {
@@ -191,6 +240,8 @@ fn weapon_system(
             let bullet_vel = bullet_dir * 1000.0;
             let bullet_pos = spatial.position + (bullet_dir * spatial.radius);
 
+            shots.send(AudioEvent::new(Sound::Shot, bullet_pos.x));
+
             commands
                 .spawn_bundle(GeometryBuilder::build_as(
                     &shapes::Circle {
@@ -210,6 +261,10 @@ fn weapon_system(
                     radius: 2.0,
                 })
                 .insert(Velocity(bullet_vel))
+                .insert(Bounding(2.0))
+                .insert(Collidable)
+                .insert(PreviousPosition(bullet_pos))
+                .insert(Bullet)
                 .insert(BoundaryRemoval);
         }
     }
@@ -238,22 +293,7 @@ fn asteroid_spawn_system(
         let velocity = Vec2::new(rng.gen_range(-w..w), rng.gen_range(-h..h));
         let velocity = (velocity - position).normalize_or_zero() * rng.gen_range(30.0..60.0);
 
-        commands
-            .spawn_bundle(GeometryBuilder::build_as(
-                &shapes::Circle {
-                    radius: r,
-                    center: Vec2::ZERO,
-                },
-                DrawMode::Fill(FillMode::color(Color::BLACK)),
-                Transform::default().with_translation(Vec3::new(position.x, position.y, 0.0)),
-            ))
-            .insert(Spatial {
-                position,
-                rotation: 0.0,
-                radius: r,
-            })
-            .insert(Velocity(velocity))
-            .insert(BoundaryRemoval);
+        spawn_asteroid(&mut commands, position, velocity, r);
     }
 }
 
@@ -0,0 +1,116 @@
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_prototype_lyon::prelude::*;
+use rand::Rng;
+
+use crate::{AngularVelocity, BoundaryRemoval, Random, Spatial, Velocity, SHIP_OUTLINE};
+
+const SPARK_COUNT: u32 = 6;
+const SPARK_SPEED: std::ops::Range<f32> = 40.0..120.0;
+const SPARK_RADIUS: f32 = 1.5;
+const SPARK_LIFETIME: Duration = Duration::from_millis(300);
+
+const DEBRIS_SPEED: std::ops::Range<f32> = 20.0..80.0;
+const DEBRIS_LIFETIME: Duration = Duration::from_secs(2);
+
+/// Ticks down, then despawns its entity. Used for transient visual-only
+/// entities (spark and debris bursts) so they vanish after a fixed lifetime
+/// even while still inside the play field; they also carry `BoundaryRemoval`.
+#[derive(Debug, Component)]
+pub(crate) struct ExpiringParticle {
+    timer: Timer,
+}
+
+impl ExpiringParticle {
+    pub(crate) fn new(lifetime: Duration) -> Self {
+        Self {
+            timer: Timer::new(lifetime, false),
+        }
+    }
+}
+
+pub(crate) struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(expiring_particle_system);
+    }
+}
+
+fn expiring_particle_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut ExpiringParticle)>,
+) {
+    for (entity, mut particle) in query.iter_mut() {
+        particle.timer.tick(time.delta());
+        if particle.timer.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Spawns a small spark burst at `position`, e.g. for an asteroid hit.
+pub(crate) fn emit_burst(commands: &mut Commands, rng: &mut Random, position: Vec2) {
+    for _ in 0..SPARK_COUNT {
+        let angle = rng.gen_range(0.0..TAU);
+        let speed = rng.gen_range(SPARK_SPEED);
+        let velocity = Vec2::new(angle.cos(), angle.sin()) * speed;
+
+        commands
+            .spawn_bundle(GeometryBuilder::build_as(
+                &shapes::Circle {
+                    radius: SPARK_RADIUS,
+                    center: Vec2::ZERO,
+                },
+                DrawMode::Fill(FillMode::color(Color::BLACK)),
+                Transform::default().with_translation(Vec3::new(position.x, position.y, 0.0)),
+            ))
+            .insert(Spatial {
+                position,
+                rotation: 0.0,
+                radius: SPARK_RADIUS,
+            })
+            .insert(Velocity(velocity))
+            .insert(ExpiringParticle::new(SPARK_LIFETIME))
+            .insert(BoundaryRemoval);
+    }
+}
+
+/// Spawns a larger, longer-lived debris burst from the ship's outline, for
+/// the player's death. Each outline edge becomes its own tumbling fragment.
+pub(crate) fn emit_debris_burst(commands: &mut Commands, rng: &mut Random, position: Vec2) {
+    let edges = SHIP_OUTLINE
+        .iter()
+        .zip(SHIP_OUTLINE.iter().cycle().skip(1))
+        .take(SHIP_OUTLINE.len());
+
+    for (&start, &end) in edges {
+        let angle = rng.gen_range(0.0..TAU);
+        let speed = rng.gen_range(DEBRIS_SPEED);
+        let velocity = Vec2::new(angle.cos(), angle.sin()) * speed;
+        let angular_velocity = rng.gen_range(-3.0_f32..3.0);
+
+        let mut path_builder = PathBuilder::new();
+        path_builder.move_to(start);
+        path_builder.line_to(end);
+
+        commands
+            .spawn_bundle(GeometryBuilder::build_as(
+                &path_builder.build(),
+                DrawMode::Stroke(StrokeMode::new(Color::BLACK, 1.0)),
+                Transform::default().with_translation(Vec3::new(position.x, position.y, 0.0)),
+            ))
+            .insert(Spatial {
+                position,
+                rotation: 0.0,
+                radius: 0.0,
+            })
+            .insert(Velocity(velocity))
+            .insert(AngularVelocity(angular_velocity))
+            .insert(ExpiringParticle::new(DEBRIS_LIFETIME))
+            .insert(BoundaryRemoval);
+    }
+}
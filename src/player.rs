@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    asteroids::Asteroid,
+    collision::{CollisionPlugin, HitEvent},
+    particles::emit_debris_burst,
+    Player, Random, Spatial, Velocity,
+};
+
+pub(crate) const STARTING_LIVES: u32 = 3;
+const INVULNERABILITY: Duration = Duration::from_secs(3);
+const BLINK_INTERVAL_MILLIS: u128 = 150;
+
+#[derive(Debug, Default)]
+pub(crate) struct Score(pub(crate) u32);
+
+#[derive(Debug, Component)]
+pub(crate) struct Lives(pub(crate) u32);
+
+/// While present, the player ignores asteroid hits and blinks to signal it.
+#[derive(Debug, Component)]
+pub(crate) struct Invulnerable(Timer);
+
+#[derive(Debug, Component)]
+struct ScoreText;
+
+#[derive(Debug, Component)]
+struct LivesText;
+
+pub(crate) struct PlayerPlugin;
+
+impl Plugin for PlayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Score(0))
+            .add_plugin(CollisionPlugin::<Asteroid, Player>::new())
+            .add_startup_system(hud_setup_system)
+            .add_system(player_hit_system)
+            .add_system(invulnerability_system)
+            .add_system(hud_update_system);
+    }
+}
+
+fn player_hit_system(
+    mut commands: Commands,
+    mut hits: EventReader<HitEvent<Asteroid, Player>>,
+    mut rng: Local<Random>,
+    mut player: Query<
+        (&mut Spatial, &mut Velocity, &mut Lives, Option<&Invulnerable>),
+        With<Player>,
+    >,
+) {
+    let mut hurt = HashSet::new();
+
+    for hit in hits.iter() {
+        // Two asteroids can hit the player in one frame; the `Invulnerable`
+        // insert below is deferred, so without this the second event would
+        // still see the player vulnerable and apply another life loss.
+        if !hurt.insert(hit.hurtable()) {
+            continue;
+        }
+
+        let Ok((mut spatial, mut velocity, mut lives, invulnerable)) =
+            player.get_mut(hit.hurtable())
+        else {
+            continue;
+        };
+        if invulnerable.is_some() {
+            continue;
+        }
+
+        emit_debris_burst(&mut commands, &mut rng, spatial.position);
+        lives.0 = lives.0.saturating_sub(1);
+
+        spatial.position = Vec2::ZERO;
+        spatial.rotation = 0.0;
+        velocity.0 = Vec2::ZERO;
+
+        commands
+            .entity(hit.hurtable())
+            .insert(Invulnerable(Timer::new(INVULNERABILITY, false)));
+    }
+}
+
+fn invulnerability_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Invulnerable, &mut Visibility)>,
+) {
+    for (entity, mut invulnerable, mut visibility) in query.iter_mut() {
+        invulnerable.0.tick(time.delta());
+
+        if invulnerable.0.finished() {
+            commands.entity(entity).remove::<Invulnerable>();
+            visibility.is_visible = true;
+            continue;
+        }
+
+        let blink_phase = invulnerable.0.elapsed().as_millis() / BLINK_INTERVAL_MILLIS;
+        visibility.is_visible = blink_phase % 2 == 0;
+    }
+}
+
+fn hud_setup_system(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let text_style = TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: 24.0,
+        color: Color::BLACK,
+    };
+
+    commands
+        .spawn_bundle(TextBundle {
+            text: Text::with_section("Score: 0", text_style.clone(), TextAlignment::default()),
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: Rect {
+                    top: Val::Px(10.0),
+                    left: Val::Px(10.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert(ScoreText);
+
+    commands
+        .spawn_bundle(TextBundle {
+            text: Text::with_section(
+                format!("Lives: {}", STARTING_LIVES),
+                text_style,
+                TextAlignment::default(),
+            ),
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: Rect {
+                    top: Val::Px(36.0),
+                    left: Val::Px(10.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert(LivesText);
+}
+
+fn hud_update_system(
+    score: Res<Score>,
+    lives: Query<&Lives, With<Player>>,
+    mut score_text: Query<&mut Text, (With<ScoreText>, Without<LivesText>)>,
+    mut lives_text: Query<&mut Text, (With<LivesText>, Without<ScoreText>)>,
+) {
+    for mut text in score_text.iter_mut() {
+        text.sections[0].value = format!("Score: {}", score.0);
+    }
+
+    if let Ok(lives) = lives.get_single() {
+        for mut text in lives_text.iter_mut() {
+            text.sections[0].value = format!("Lives: {}", lives.0);
+        }
+    }
+}
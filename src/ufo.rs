@@ -0,0 +1,174 @@
+use std::collections::HashSet;
+
+use bevy::{core::FixedTimestep, prelude::*};
+use bevy_prototype_lyon::prelude::*;
+use rand::Rng;
+
+use crate::{
+    asteroids::Bullet,
+    audio::{AudioEvent, Sound},
+    boundary::Bounding,
+    collision::{Collidable, CollisionPlugin, HitEvent, PreviousPosition},
+    particles::emit_burst,
+    BoundaryRemoval, Player, Random, Spatial, SpeedLimit, Velocity, TIME_STEP,
+};
+
+const UFO_RADIUS: f32 = 14.0;
+const UFO_SPEED_LIMIT: f32 = 150.0;
+
+/// Neighbors farther than this are ignored by the boids rules entirely.
+const NEIGHBOR_RADIUS: f32 = 120.0;
+/// Neighbors closer than this trigger separation steering.
+const PERSONAL_SPACE: f32 = 40.0;
+
+const SEPARATION_WEIGHT: f32 = 1.5;
+const ALIGNMENT_WEIGHT: f32 = 1.0;
+const COHESION_WEIGHT: f32 = 1.0;
+const PLAYER_ATTRACTION_WEIGHT: f32 = 0.2;
+
+#[derive(Debug, Component, Default)]
+pub(crate) struct Ufo;
+
+/// Marks an entity as a member of a boid flock steered by `boids_system`.
+#[derive(Debug, Component, Default)]
+pub(crate) struct Flock;
+
+/// Lets bullets shoot UFOs down through the existing collision machinery.
+pub(crate) struct UfoPlugin;
+
+impl Plugin for UfoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(CollisionPlugin::<Bullet, Ufo>::new())
+            .add_system(ufo_spawn_system.with_run_criteria(FixedTimestep::step(4.0)))
+            .add_system(ufo_hit_system);
+    }
+}
+
+fn ufo_hit_system(
+    mut commands: Commands,
+    mut hits: EventReader<HitEvent<Bullet, Ufo>>,
+    mut rng: Local<Random>,
+    mut explosions: EventWriter<AudioEvent>,
+    ufos: Query<&Spatial>,
+) {
+    let mut destroyed = HashSet::new();
+
+    for hit in hits.iter() {
+        commands.entity(hit.hittable()).despawn();
+
+        // Two bullets can hit the same UFO in one frame; despawn commands
+        // are deferred, so without this the second event would still see
+        // it alive and emit another spark burst and explosion sound.
+        if !destroyed.insert(hit.hurtable()) {
+            continue;
+        }
+        commands.entity(hit.hurtable()).despawn();
+
+        if let Ok(spatial) = ufos.get(hit.hurtable()) {
+            emit_burst(&mut commands, &mut rng, spatial.position);
+            explosions.send(AudioEvent::new(Sound::Explosion, spatial.position.x));
+        }
+    }
+}
+
+pub(crate) fn ufo_spawn_system(
+    window: Res<WindowDescriptor>,
+    mut rng: Local<Random>,
+    mut commands: Commands,
+) {
+    let w = window.width / 2.0;
+    let h = window.height / 2.0;
+
+    let position = if rng.gen_bool(1.0 / 2.0) {
+        Vec2::new(
+            rng.gen_range(-w..w),
+            if rng.gen_bool(1.0 / 2.0) { h } else { -h },
+        )
+    } else {
+        Vec2::new(
+            if rng.gen_bool(1.0 / 2.0) { w } else { -w },
+            rng.gen_range(-h..h),
+        )
+    };
+
+    commands
+        .spawn_bundle(GeometryBuilder::build_as(
+            &shapes::RegularPolygon {
+                sides: 6,
+                feature: shapes::RegularPolygonFeature::Radius(UFO_RADIUS),
+                ..Default::default()
+            },
+            DrawMode::Fill(FillMode::color(Color::BLACK)),
+            Transform::default().with_translation(Vec3::new(position.x, position.y, 0.0)),
+        ))
+        .insert(Spatial {
+            position,
+            rotation: 0.0,
+            radius: UFO_RADIUS,
+        })
+        .insert(Velocity::default())
+        .insert(SpeedLimit(UFO_SPEED_LIMIT))
+        .insert(Bounding(UFO_RADIUS))
+        .insert(Collidable)
+        .insert(PreviousPosition(position))
+        .insert(Ufo)
+        .insert(Flock)
+        .insert(BoundaryRemoval);
+}
+
+/// Classic boids steering: each flock member weighs separation, alignment
+/// and cohesion against its neighbors, plus a weak pull toward the player,
+/// and adds the result, scaled by `TIME_STEP`, into `Velocity` for
+/// `speed_limit_system` to clamp afterwards.
+pub(crate) fn boids_system(
+    player: Query<&Spatial, With<Player>>,
+    mut flock: Query<(Entity, &Spatial, &mut Velocity), With<Flock>>,
+) {
+    let members: Vec<(Entity, Vec2, Vec2)> = flock
+        .iter()
+        .map(|(entity, spatial, velocity)| (entity, spatial.position, velocity.0))
+        .collect();
+    let player_position = player.iter().next().map(|spatial| spatial.position);
+
+    for (entity, spatial, mut velocity) in flock.iter_mut() {
+        let mut separation = Vec2::ZERO;
+        let mut alignment = Vec2::ZERO;
+        let mut cohesion = Vec2::ZERO;
+        let mut neighbor_count = 0;
+
+        for &(other_entity, other_position, other_velocity) in &members {
+            if other_entity == entity {
+                continue;
+            }
+
+            let offset = spatial.position - other_position;
+            let distance = offset.length();
+            if distance > NEIGHBOR_RADIUS {
+                continue;
+            }
+
+            if distance < PERSONAL_SPACE {
+                separation += offset.normalize_or_zero() / distance.max(1.0);
+            }
+            alignment += other_velocity;
+            cohesion += other_position;
+            neighbor_count += 1;
+        }
+
+        let mut steering = separation * SEPARATION_WEIGHT;
+        if neighbor_count > 0 {
+            let average_velocity = alignment / neighbor_count as f32;
+            let average_position = cohesion / neighbor_count as f32;
+            steering += (average_velocity - velocity.0) * ALIGNMENT_WEIGHT;
+            steering += (average_position - spatial.position) * COHESION_WEIGHT;
+        }
+
+        if let Some(player_position) = player_position {
+            steering += (player_position - spatial.position).normalize_or_zero()
+                * PLAYER_ATTRACTION_WEIGHT
+                * UFO_SPEED_LIMIT;
+        }
+
+        velocity.0 += steering * TIME_STEP;
+    }
+}